@@ -0,0 +1,67 @@
+/// Compressed Sparse Row adjacency for a graph with `node_count` dense
+/// `usize`-indexed nodes. Out-edges of node `i` live in the slice
+/// `targets[row_offsets[i]..row_offsets[i + 1]]`, so looking up a node's
+/// neighbors or a specific edge's weight is O(degree) instead of scanning
+/// every edge in the graph.
+#[derive(Debug)]
+pub(crate) struct Csr {
+    row_offsets: Vec<usize>,
+    targets: Vec<(usize, f64)>,
+}
+
+impl Csr {
+    /// Builds the CSR from `(source, target, weight)` triples keyed by the
+    /// dense node indices assigned by the caller.
+    pub(crate) fn build(node_count: usize, edges: &[(usize, usize, f64)]) -> Csr {
+        let mut sorted = edges.to_vec();
+        sorted.sort_by_key(|&(source, _, _)| source);
+
+        let mut row_offsets = vec![0usize; node_count + 1];
+        for &(source, _, _) in &sorted {
+            row_offsets[source + 1] += 1;
+        }
+        for index in 0..node_count {
+            row_offsets[index + 1] += row_offsets[index];
+        }
+
+        let targets = sorted
+            .into_iter()
+            .map(|(_, target, weight)| (target, weight))
+            .collect();
+
+        Csr {
+            row_offsets,
+            targets,
+        }
+    }
+
+    pub(crate) fn neighbors(&self, node: usize) -> &[(usize, f64)] {
+        &self.targets[self.row_offsets[node]..self.row_offsets[node + 1]]
+    }
+
+    pub(crate) fn weight(&self, from: usize, to: usize) -> f64 {
+        self.neighbors(from)
+            .iter()
+            .find(|&&(target, _)| target == to)
+            .map(|&(_, weight)| weight)
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbors_and_weight_are_scoped_to_the_source_row() {
+        // 0 -> 1 (1.0), 0 -> 2 (2.0), 1 -> 2 (3.0)
+        let edges = [(0, 1, 1.0), (0, 2, 2.0), (1, 2, 3.0)];
+        let csr = Csr::build(3, &edges);
+
+        assert_eq!(csr.neighbors(0).len(), 2);
+        assert_eq!(csr.neighbors(1).len(), 1);
+        assert_eq!(csr.neighbors(2).len(), 0);
+        assert_eq!(csr.weight(0, 2), 2.0);
+        assert_eq!(csr.weight(1, 2), 3.0);
+    }
+}
@@ -0,0 +1,562 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::csr::Csr;
+use crate::heap::QuaternaryHeap;
+
+/// A `f64` wrapper that is `Ord` so edge costs can live in the frontier heap.
+/// Graphs with `NaN` edge weights are rejected at construction time rather
+/// than silently producing an unordered heap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Cost(f64);
+
+impl Cost {
+    fn new(value: f64) -> Cost {
+        assert!(!value.is_nan(), "edge cost must not be NaN");
+        Cost(value)
+    }
+}
+
+impl Eq for Cost {}
+
+impl Ord for Cost {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0).unwrap()
+    }
+}
+
+impl PartialOrd for Cost {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// One entry on the search frontier: a candidate node index, the true cost
+/// `g` of the path that reached it, and the `priority` the heap orders it
+/// by. For plain Dijkstra `priority == g`; for A* `priority == g + h(node)`
+/// while `g` is kept alongside so staleness can still be checked against
+/// the true path cost. The heap pops the lowest `priority` first; a popped
+/// entry whose `g` is stale (greater than the node's current recorded cost)
+/// is skipped by the caller instead of being removed from the heap (lazy
+/// deletion).
+#[derive(Debug, Clone, Copy)]
+struct FrontierEntry {
+    priority: Cost,
+    g: f64,
+    node: usize,
+}
+
+impl PartialEq for FrontierEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl Eq for FrontierEntry {}
+
+impl PartialOrd for FrontierEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FrontierEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+/// Search state for a single run of `djikstra`/`astar`. Nodes are addressed
+/// by a dense `usize` index rather than by id, and the graph itself is held
+/// as a `Csr` so relaxing a node's out-edges is O(degree) instead of
+/// scanning every edge.
+#[derive(Debug)]
+pub(crate) struct Arena {
+    nodes: Vec<Node>,
+    indices: HashMap<String, usize>,
+    raw_edges: Vec<(usize, usize, f64)>,
+    csr: Csr,
+    costs: Vec<f64>,
+    parents: Vec<Option<usize>>,
+    processed: Vec<bool>,
+    start: usize,
+    end: usize,
+}
+
+impl Arena {
+    pub(crate) fn new(start: &Node, end: &Node) -> Arena {
+        let mut arena = Arena {
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+            raw_edges: Vec::new(),
+            csr: Csr::build(0, &[]),
+            costs: Vec::new(),
+            parents: Vec::new(),
+            processed: Vec::new(),
+            start: 0,
+            end: 0,
+        };
+        arena.start = arena.intern(start);
+        arena.end = arena.intern(end);
+        // `end`'s cost must be set first: when start == end (a degenerate
+        // but valid single-node search) the two indices are the same slot,
+        // and start's 0.0 has to be the one left standing.
+        arena.costs[arena.end] = std::f64::INFINITY;
+        arena.costs[arena.start] = 0.0;
+        arena
+    }
+
+    /// Assigns `node` a dense index the first time it's seen, reusing the
+    /// existing index on subsequent calls with the same id.
+    fn intern(&mut self, node: &Node) -> usize {
+        if let Some(&index) = self.indices.get(&node.id) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.indices.insert(node.id.clone(), index);
+        self.nodes.push(node.clone());
+        self.costs.push(std::f64::MAX);
+        self.parents.push(None);
+        self.processed.push(false);
+        index
+    }
+
+    fn index_of(&self, node: &Node) -> usize {
+        self.indices[&node.id]
+    }
+
+    pub(crate) fn add_node(mut self, node: &Node) -> Arena {
+        self.intern(node);
+        self
+    }
+
+    pub(crate) fn add_nodes(self, nodes: &[&Node]) -> Arena {
+        nodes.iter().fold(self, |acc, node| acc.add_node(node))
+    }
+
+    pub(crate) fn add_edge(mut self, edge: &Edge) -> Arena {
+        assert!(!edge.weight.is_nan(), "edge weight must not be NaN");
+        let start = self.intern(edge.start_node);
+        let end = self.intern(edge.end_node);
+        self.raw_edges.push((start, end, edge.weight));
+        self
+    }
+
+    pub(crate) fn add_edges(self, edges: &[&Edge]) -> Arena {
+        edges.iter().fold(self, |acc, edge| acc.add_edge(edge))
+    }
+
+    /// Builds `self.csr` from every edge accumulated so far, against the
+    /// final node count. Deferred to here (one O(E log E) build) instead of
+    /// rebuilding on every `add_edge` call, and done against `nodes.len()`
+    /// at search time so a node interned after the last edge (or a graph
+    /// with no edges at all) still gets a `row_offsets` slot instead of
+    /// `find_neighbors` indexing out of bounds.
+    fn finalize_csr(&mut self) {
+        self.csr = Csr::build(self.nodes.len(), &self.raw_edges);
+    }
+
+    fn find_neighbors(&self, node: usize) -> Vec<usize> {
+        self.csr
+            .neighbors(node)
+            .iter()
+            .map(|&(target, _)| target)
+            .collect()
+    }
+
+    fn mark_node_processed(&mut self, node: usize) {
+        self.processed[node] = true;
+    }
+
+    fn get_cost(&self, node: usize) -> f64 {
+        self.costs[node]
+    }
+
+    fn get_weight(&self, node_one: usize, node_two: usize) -> f64 {
+        self.csr.weight(node_one, node_two)
+    }
+
+    /// O(V) scan kept around as a debug/cross-check helper; the Dijkstra
+    /// loop itself is driven by the heap frontier in `djikstra`.
+    fn find_lowest_cost_node(&self) -> Option<usize> {
+        (0..self.nodes.len())
+            .filter(|&index| !self.processed[index])
+            .min_by(|&a, &b| self.costs[a].partial_cmp(&self.costs[b]).unwrap())
+    }
+
+    /// Lazily yields `(node, cost, parent)` in order of increasing shortest-
+    /// path distance from `self.start`, driven by the same heap frontier
+    /// `djikstra`/`astar` use. Lets callers implement their own stopping
+    /// condition (within radius R, first K nearest, multi-target) without
+    /// recomputing the search from scratch.
+    pub(crate) fn dijkstra_iter(self) -> SearchIter<impl Fn(&Node) -> f64> {
+        self.search_iter(|_node| 0.0)
+    }
+
+    /// Builds the shared heap-frontier iterator that `djikstra` (via
+    /// `dijkstra_iter`, `h = 0`) drives to completion and `astar` drives
+    /// until `self.end` is settled.
+    fn search_iter<H>(mut self, heuristic: H) -> SearchIter<H>
+    where
+        H: Fn(&Node) -> f64,
+    {
+        self.finalize_csr();
+
+        let mut frontier = QuaternaryHeap::new();
+        let g0 = self.get_cost(self.start);
+        frontier.push(FrontierEntry {
+            priority: Cost::new(g0 + heuristic(&self.nodes[self.start])),
+            g: g0,
+            node: self.start,
+        });
+
+        SearchIter {
+            arena: self,
+            frontier,
+            heuristic,
+        }
+    }
+
+    pub(crate) fn djikstra(self) -> String {
+        let mut search = self.dijkstra_iter();
+        for _ in &mut search {}
+        search.arena.build_path()
+    }
+
+    /// Goal-directed variant of `djikstra`. `h` estimates the remaining cost
+    /// from a node to `self.end` and must be admissible (never overestimate
+    /// the true remaining cost) for the returned path to stay optimal. The
+    /// frontier is ordered by `g + h(node)` instead of `g` alone, so nodes
+    /// that look close to the goal are explored before distant ones. The
+    /// search stops as soon as `self.end` is settled rather than exhausting
+    /// the graph.
+    pub(crate) fn astar<H>(self, h: H) -> String
+    where
+        H: Fn(&Node) -> f64,
+    {
+        let end_id = self.nodes[self.end].id.clone();
+        let mut search = self.search_iter(h);
+        for (node, _, _) in &mut search {
+            if node.id == end_id {
+                break;
+            }
+        }
+        search.arena.build_path()
+    }
+
+    /// Walks `self.parents` back from `self.end` to reconstruct the
+    /// `"a -> b -> ..."` path string. Shared by `djikstra` and `astar`.
+    fn build_path(&self) -> String {
+        let mut path = vec![self.end];
+        let mut current = self.end;
+        while let Some(parent) = self.parents[current] {
+            path.push(parent);
+            current = parent;
+        }
+
+        path.iter()
+            .map(|&index| &self.nodes[index].id)
+            .fold(String::new(), |acc, id| format!("{} -> {}", id, acc))
+    }
+}
+
+/// Lazy driver for `Arena::dijkstra_iter`/`Arena::astar`: each `next()` pops
+/// the frontier's minimum entry, skips it if stale, relaxes its out-edges,
+/// and returns the now-settled `(node, cost, parent)`. Built by
+/// `Arena::search_iter`, which seeds the frontier with `start` under
+/// `heuristic`; a zero heuristic recovers plain Dijkstra order.
+pub(crate) struct SearchIter<H>
+where
+    H: Fn(&Node) -> f64,
+{
+    arena: Arena,
+    frontier: QuaternaryHeap<FrontierEntry>,
+    heuristic: H,
+}
+
+impl<H> Iterator for SearchIter<H>
+where
+    H: Fn(&Node) -> f64,
+{
+    type Item = (Node, f64, Option<Node>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let FrontierEntry { g, node, .. } = self.frontier.pop()?;
+            if g > self.arena.get_cost(node) {
+                continue;
+            }
+
+            for neighbor in self.arena.find_neighbors(node) {
+                let new_cost = g + self.arena.get_weight(node, neighbor);
+                if self.arena.get_cost(neighbor) > new_cost {
+                    self.arena.costs[neighbor] = new_cost;
+                    self.arena.parents[neighbor] = Some(node);
+                    self.frontier.push(FrontierEntry {
+                        priority: Cost::new(new_cost + (self.heuristic)(&self.arena.nodes[neighbor])),
+                        g: new_cost,
+                        node: neighbor,
+                    });
+                }
+            }
+
+            self.arena.processed[node] = true;
+
+            let parent = self.arena.parents[node].map(|index| self.arena.nodes[index].clone());
+            return Some((self.arena.nodes[node].clone(), g, parent));
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Node {
+    pub(crate) id: String,
+}
+
+impl Node {
+    pub(crate) fn new_with_id(id: String) -> Node {
+        Node { id: id }
+    }
+    fn new() -> Node {
+        Node {
+            id: Uuid::new_v4().to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Edge<'a> {
+    pub(crate) weight: f64,
+    pub(crate) end_node: &'a Node,
+    pub(crate) start_node: &'a Node,
+}
+
+impl<'a> Edge<'a> {
+    pub(crate) fn new(weight: f64, start_node: &'a Node, end_node: &'a Node) -> Edge<'a> {
+        Edge {
+            weight: weight,
+            start_node: start_node,
+            end_node: end_node,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn find_neighbors_test() {
+        let start = Node::new();
+        let one = Node::new();
+        let two = Node::new();
+        let three = Node::new();
+        let four = Node::new();
+        let not_a_neighbor = Node::new();
+        let start_one = Edge::new(0.0, &start, &one);
+        let start_two = Edge::new(0.0, &start, &two);
+        let start_three = Edge::new(0.0, &start, &three);
+        let start_four = Edge::new(0.0, &start, &four);
+        let four_not = Edge::new(0.0, &four, &not_a_neighbor);
+        let nodes = [&one, &two, &three, &four, &not_a_neighbor];
+        let edges = [&start_one, &start_two, &start_three, &start_four, &four_not];
+        let mut arena = Arena::new(&start, &four)
+            .add_nodes(&nodes)
+            .add_edges(&edges);
+        arena.finalize_csr();
+        let start_index = arena.index_of(&start);
+        let four_index = arena.index_of(&four);
+        let two_index = arena.index_of(&two);
+        assert_eq!(arena.find_neighbors(start_index).len(), 4);
+        assert_eq!(arena.find_neighbors(four_index).len(), 1);
+        assert_eq!(arena.find_neighbors(two_index).len(), 0);
+    }
+
+    #[test]
+    fn mark_node_processed_test() {
+        let start = Node::new();
+        let mut arena = Arena::new(&start, &start);
+        let start_index = arena.index_of(&start);
+        arena.mark_node_processed(start_index);
+        assert_eq!(arena.processed, vec![true]);
+    }
+
+    #[test]
+    fn find_lowest_cost_node_test() {
+        let start = Node::new();
+        let one = Node::new();
+        let two = Node::new();
+        let three = Node::new();
+        let four = Node::new();
+        let not_a_neighbor = Node::new();
+        let start_one = Edge::new(0.0, &start, &one);
+        let start_two = Edge::new(0.0, &start, &two);
+        let start_three = Edge::new(0.0, &start, &three);
+        let start_four = Edge::new(0.0, &start, &four);
+        let four_not = Edge::new(0.0, &four, &not_a_neighbor);
+        let nodes = [&start, &one, &two, &three, &four, &not_a_neighbor];
+        let edges = [&start_one, &start_two, &start_three, &start_four, &four_not];
+        let arena = Arena::new(&start, &not_a_neighbor)
+            .add_nodes(&nodes)
+            .add_edges(&edges);
+        let start_index = arena.index_of(&start);
+        assert_eq!(arena.find_lowest_cost_node(), Some(start_index));
+    }
+
+    #[test]
+    fn djikstra_test() {
+        let book = Node::new_with_id("book".to_string());
+        let poster = Node::new_with_id("poster".to_string());
+        let lp = Node::new_with_id("lp".to_string());
+        let drums = Node::new_with_id("drums".to_string());
+        let bassguitar = Node::new_with_id("bassguitar".to_string());
+        let piano = Node::new_with_id("piano".to_string());
+        let start_poster = Edge::new(0.0, &book, &poster);
+        let book_lp = Edge::new(5.0, &book, &lp);
+        let poster_bassguitar = Edge::new(30.0, &poster, &bassguitar);
+        let poster_drums = Edge::new(35.0, &poster, &drums);
+        let lp_drums = Edge::new(20.0, &lp, &drums);
+        let lp_bassguitar = Edge::new(15.0, &lp, &bassguitar);
+        let bassguitar_piano = Edge::new(20.0, &bassguitar, &piano);
+        let drums_piano = Edge::new(10.0, &drums, &piano);
+
+        let nodes = [&book, &poster, &lp, &drums, &bassguitar, &piano];
+        let edges = [
+            &start_poster,
+            &book_lp,
+            &poster_bassguitar,
+            &poster_drums,
+            &lp_drums,
+            &lp_bassguitar,
+            &bassguitar_piano,
+            &drums_piano,
+        ];
+
+        let arena = Arena::new(&book, &piano)
+            .add_nodes(&nodes)
+            .add_edges(&edges);
+
+        assert_eq!("book -> lp -> drums -> piano -> ", arena.djikstra())
+    }
+
+    #[test]
+    fn astar_test() {
+        let book = Node::new_with_id("book".to_string());
+        let poster = Node::new_with_id("poster".to_string());
+        let lp = Node::new_with_id("lp".to_string());
+        let drums = Node::new_with_id("drums".to_string());
+        let bassguitar = Node::new_with_id("bassguitar".to_string());
+        let piano = Node::new_with_id("piano".to_string());
+        let start_poster = Edge::new(0.0, &book, &poster);
+        let book_lp = Edge::new(5.0, &book, &lp);
+        let poster_bassguitar = Edge::new(30.0, &poster, &bassguitar);
+        let poster_drums = Edge::new(35.0, &poster, &drums);
+        let lp_drums = Edge::new(20.0, &lp, &drums);
+        let lp_bassguitar = Edge::new(15.0, &lp, &bassguitar);
+        let bassguitar_piano = Edge::new(20.0, &bassguitar, &piano);
+        let drums_piano = Edge::new(10.0, &drums, &piano);
+
+        let nodes = [&book, &poster, &lp, &drums, &bassguitar, &piano];
+        let edges = [
+            &start_poster,
+            &book_lp,
+            &poster_bassguitar,
+            &poster_drums,
+            &lp_drums,
+            &lp_bassguitar,
+            &bassguitar_piano,
+            &drums_piano,
+        ];
+
+        let arena = Arena::new(&book, &piano)
+            .add_nodes(&nodes)
+            .add_edges(&edges);
+
+        // A zero heuristic is trivially admissible, so this should settle
+        // on the same optimal path as plain djikstra.
+        assert_eq!(
+            "book -> lp -> drums -> piano -> ",
+            arena.astar(|_node| 0.0)
+        )
+    }
+
+    #[test]
+    fn dijkstra_iter_settles_nodes_in_increasing_distance_order() {
+        let book = Node::new_with_id("book".to_string());
+        let poster = Node::new_with_id("poster".to_string());
+        let lp = Node::new_with_id("lp".to_string());
+        let drums = Node::new_with_id("drums".to_string());
+        let bassguitar = Node::new_with_id("bassguitar".to_string());
+        let piano = Node::new_with_id("piano".to_string());
+        let start_poster = Edge::new(0.0, &book, &poster);
+        let book_lp = Edge::new(5.0, &book, &lp);
+        let poster_bassguitar = Edge::new(30.0, &poster, &bassguitar);
+        let poster_drums = Edge::new(35.0, &poster, &drums);
+        let lp_drums = Edge::new(20.0, &lp, &drums);
+        let lp_bassguitar = Edge::new(15.0, &lp, &bassguitar);
+        let bassguitar_piano = Edge::new(20.0, &bassguitar, &piano);
+        let drums_piano = Edge::new(10.0, &drums, &piano);
+
+        let nodes = [&book, &poster, &lp, &drums, &bassguitar, &piano];
+        let edges = [
+            &start_poster,
+            &book_lp,
+            &poster_bassguitar,
+            &poster_drums,
+            &lp_drums,
+            &lp_bassguitar,
+            &bassguitar_piano,
+            &drums_piano,
+        ];
+
+        let arena = Arena::new(&book, &piano)
+            .add_nodes(&nodes)
+            .add_edges(&edges);
+
+        let settled: Vec<(String, f64)> = arena
+            .dijkstra_iter()
+            .map(|(node, cost, _)| (node.id, cost))
+            .collect();
+
+        let costs: Vec<f64> = settled.iter().map(|(_, cost)| *cost).collect();
+        let mut sorted_costs = costs.clone();
+        sorted_costs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(costs, sorted_costs);
+
+        let piano_settled = settled
+            .iter()
+            .find(|(id, _)| id == "piano")
+            .expect("piano must be settled");
+        assert_eq!(piano_settled.1, 35.0);
+    }
+
+    #[test]
+    fn djikstra_on_a_single_edgeless_node_does_not_panic() {
+        let only = Node::new();
+        assert_eq!(format!("{} -> ", only.id), Arena::new(&only, &only).djikstra());
+    }
+
+    #[test]
+    fn start_equal_to_end_settles_at_cost_zero() {
+        let only = Node::new();
+        let mut settled = Arena::new(&only, &only).dijkstra_iter();
+        let (node, cost, parent) = settled.next().expect("single node must settle");
+        assert_eq!(node.id, only.id);
+        assert_eq!(cost, 0.0);
+        assert_eq!(parent, None);
+    }
+
+    #[test]
+    fn djikstra_settles_a_node_interned_after_the_last_edge() {
+        let start = Node::new();
+        let lonely = Node::new();
+        let end = Node::new();
+        let start_end = Edge::new(1.0, &start, &end);
+
+        let arena = Arena::new(&start, &end)
+            .add_edges(&[&start_end])
+            .add_node(&lonely);
+
+        assert_eq!(arena.djikstra(), format!("{} -> {} -> ", start.id, end.id));
+    }
+}
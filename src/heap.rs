@@ -0,0 +1,92 @@
+/// Arity of the heap. A 4-ary heap is half as deep as a binary heap, and
+/// each node's children sit next to each other in the `Vec`, so the extra
+/// comparisons per level stay cache-friendly.
+const HEAP_ARITY: usize = 4;
+
+/// A min-ordered d-ary heap over a `Vec`, generic over any `Ord` payload so
+/// it isn't tied to a particular search's frontier entry type.
+#[derive(Debug)]
+pub(crate) struct QuaternaryHeap<T: Ord> {
+    items: Vec<T>,
+}
+
+impl<T: Ord> QuaternaryHeap<T> {
+    pub(crate) fn new() -> QuaternaryHeap<T> {
+        QuaternaryHeap { items: Vec::new() }
+    }
+
+    pub(crate) fn push(&mut self, item: T) {
+        self.items.push(item);
+        self.sift_up(self.items.len() - 1);
+    }
+
+    pub(crate) fn pop(&mut self) -> Option<T> {
+        if self.items.is_empty() {
+            return None;
+        }
+        let last = self.items.len() - 1;
+        self.items.swap(0, last);
+        let popped = self.items.pop();
+        if !self.items.is_empty() {
+            self.sift_down(0);
+        }
+        popped
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / HEAP_ARITY;
+            if self.items[index] < self.items[parent] {
+                self.items.swap(index, parent);
+                index = parent;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let first_child = index * HEAP_ARITY + 1;
+            if first_child >= self.items.len() {
+                break;
+            }
+            let last_child = (first_child + HEAP_ARITY).min(self.items.len());
+            let smallest = (first_child..last_child)
+                .min_by(|&a, &b| self.items[a].cmp(&self.items[b]))
+                .unwrap();
+            if self.items[smallest] < self.items[index] {
+                self.items.swap(smallest, index);
+                index = smallest;
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pops_items_in_ascending_order() {
+        let mut heap = QuaternaryHeap::new();
+        for value in [5, 1, 4, 2, 8, 0, 9, 3, 7, 6] {
+            heap.push(value);
+        }
+
+        let mut popped = Vec::new();
+        while let Some(value) = heap.pop() {
+            popped.push(value);
+        }
+
+        assert_eq!(popped, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn pop_on_empty_heap_is_none() {
+        let mut heap: QuaternaryHeap<i32> = QuaternaryHeap::new();
+        assert_eq!(heap.pop(), None);
+    }
+}
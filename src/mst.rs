@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use crate::graph::{Edge, Node};
+use crate::union_find::UnionFind;
+
+/// The edges Kruskal's algorithm accepted into the minimum spanning tree,
+/// plus their combined weight.
+#[derive(Debug)]
+pub(crate) struct MinimumSpanningTree<'a> {
+    pub(crate) edges: Vec<&'a Edge<'a>>,
+    pub(crate) total_weight: f64,
+}
+
+/// Runs Kruskal's algorithm over `nodes`/`edges`: sort edges by weight
+/// ascending, and keep an edge only if its endpoints are in different
+/// union-find sets, stopping once `nodes.len() - 1` edges have been
+/// accepted (or the edges run out, for a disconnected graph).
+pub(crate) fn minimum_spanning_tree<'a>(
+    nodes: &[&'a Node],
+    edges: &[&'a Edge<'a>],
+) -> MinimumSpanningTree<'a> {
+    let mut indices = HashMap::new();
+    for (index, node) in nodes.iter().enumerate() {
+        indices.insert(node.id.clone(), index);
+    }
+
+    let mut sorted_edges: Vec<&'a Edge<'a>> = edges.to_vec();
+    sorted_edges.sort_by(|a, b| a.weight.partial_cmp(&b.weight).unwrap());
+
+    let mut forest = UnionFind::new(nodes.len());
+    let mut accepted = Vec::new();
+    let mut total_weight = 0.0;
+    let edges_needed = nodes.len().saturating_sub(1);
+
+    for edge in sorted_edges {
+        if accepted.len() == edges_needed {
+            break;
+        }
+
+        let start = indices[&edge.start_node.id];
+        let end = indices[&edge.end_node.id];
+        if forest.union(start, end) {
+            total_weight += edge.weight;
+            accepted.push(edge);
+        }
+    }
+
+    MinimumSpanningTree {
+        edges: accepted,
+        total_weight,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kruskal_skips_cycles_and_keeps_the_cheapest_edges() {
+        let a = Node::new_with_id("a".to_string());
+        let b = Node::new_with_id("b".to_string());
+        let c = Node::new_with_id("c".to_string());
+        let d = Node::new_with_id("d".to_string());
+
+        let a_b = Edge::new(1.0, &a, &b);
+        let b_c = Edge::new(2.0, &b, &c);
+        let c_d = Edge::new(3.0, &c, &d);
+        let a_d = Edge::new(4.0, &a, &d);
+        let a_c = Edge::new(5.0, &a, &c);
+
+        let nodes = [&a, &b, &c, &d];
+        let edges = [&a_b, &b_c, &c_d, &a_d, &a_c];
+
+        let mst = minimum_spanning_tree(&nodes, &edges);
+
+        assert_eq!(mst.edges.len(), 3);
+        assert_eq!(mst.total_weight, 6.0);
+    }
+
+    #[test]
+    fn disconnected_graph_yields_fewer_than_n_minus_one_edges() {
+        let a = Node::new_with_id("a".to_string());
+        let b = Node::new_with_id("b".to_string());
+        let c = Node::new_with_id("c".to_string());
+        let d = Node::new_with_id("d".to_string());
+
+        let a_b = Edge::new(1.0, &a, &b);
+        let c_d = Edge::new(1.0, &c, &d);
+
+        let nodes = [&a, &b, &c, &d];
+        let edges = [&a_b, &c_d];
+
+        let mst = minimum_spanning_tree(&nodes, &edges);
+
+        assert_eq!(mst.edges.len(), 2);
+        assert_eq!(mst.total_weight, 2.0);
+    }
+}
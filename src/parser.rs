@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+
+use crate::graph::{Arena, Edge, Node};
+
+/// A parsed edge before its `Node`s exist as borrowable values: indices
+/// into the node list that will be built alongside it, plus the weight.
+pub(crate) struct EdgeSpec {
+    start: usize,
+    end: usize,
+    weight: f64,
+}
+
+/// Parses a whitespace-separated adjacency matrix: one row of weights per
+/// line, one row per node. A weight of `0` means "no edge", and a row
+/// shorter than the others has no edges to the columns it leaves off at
+/// its end; anything else becomes a directed edge from the row's node to
+/// the column's node. Cells are split on whitespace, so an empty cell in
+/// the middle of a row is not a cell: the cells after it shift one column
+/// left. A matrix carries no textual labels, so nodes are
+/// labelled by their row index.
+pub(crate) fn parse_adjacency_matrix(text: &str) -> (Vec<Node>, Vec<EdgeSpec>) {
+    let rows: Vec<Vec<f64>> = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.split_whitespace()
+                .map(|cell| cell.parse().expect("adjacency matrix cell must be a number"))
+                .collect()
+        })
+        .collect();
+
+    let nodes: Vec<Node> = (0..rows.len())
+        .map(|index| Node::new_with_id(index.to_string()))
+        .collect();
+
+    let mut edges = Vec::new();
+    for (row, weights) in rows.iter().enumerate() {
+        for (col, &weight) in weights.iter().enumerate() {
+            if weight != 0.0 {
+                edges.push(EdgeSpec {
+                    start: row,
+                    end: col,
+                    weight,
+                });
+            }
+        }
+    }
+
+    (nodes, edges)
+}
+
+/// Looks up `label`'s node index, creating a fresh node the first time the
+/// label is seen (reusing `Node::new_with_id`).
+fn intern_label(label: &str, nodes: &mut Vec<Node>, indices: &mut HashMap<String, usize>) -> usize {
+    if let Some(&index) = indices.get(label) {
+        return index;
+    }
+    let index = nodes.len();
+    indices.insert(label.to_string(), index);
+    nodes.push(Node::new_with_id(label.to_string()));
+    index
+}
+
+/// Parses a line-oriented edge list, one edge per line:
+/// `start_label end_label weight`, e.g. `book lp 5.0`. Nodes are
+/// created/deduplicated by their textual labels as they're first seen.
+pub(crate) fn parse_edge_list(text: &str) -> (Vec<Node>, Vec<EdgeSpec>) {
+    let mut nodes = Vec::new();
+    let mut indices = HashMap::new();
+    let mut edges = Vec::new();
+
+    for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let mut parts = line.split_whitespace();
+        let start_label = parts.next().expect("edge list line needs a start label");
+        let end_label = parts.next().expect("edge list line needs an end label");
+        let weight: f64 = parts
+            .next()
+            .expect("edge list line needs a weight")
+            .parse()
+            .expect("edge list weight must be a number");
+
+        let start = intern_label(start_label, &mut nodes, &mut indices);
+        let end = intern_label(end_label, &mut nodes, &mut indices);
+        edges.push(EdgeSpec { start, end, weight });
+    }
+
+    (nodes, edges)
+}
+
+/// Looks up `label`'s row/node index among already-parsed `nodes`, e.g. to
+/// turn `parse_edge_list`'s `"book"`/`"piano"` labels into the indices
+/// `build_graph` wants for `start`/`end`.
+pub(crate) fn index_of_label(nodes: &[Node], label: &str) -> usize {
+    nodes
+        .iter()
+        .position(|node| node.id == label)
+        .unwrap_or_else(|| panic!("no node labelled {:?}", label))
+}
+
+/// Resolves `edge_specs` against `nodes` into borrowing `Edge`s, and builds
+/// the search `Arena` over `nodes`/those edges. Kept as its own step,
+/// separate from `parse_adjacency_matrix`/`parse_edge_list`, because an
+/// owned `Vec<Node>` and a `Vec<Edge>` borrowing it can't both be handed
+/// back from the same function call -- returning the `Vec<Node>` would
+/// move it out from under the `Edge`s still borrowing it. Calling this
+/// against the caller's own `nodes` binding instead means `nodes` and the
+/// returned `edges` are both still around afterwards, so callers can feed
+/// them straight into other `nodes`-and-`edges` consumers such as
+/// `minimum_spanning_tree`.
+pub(crate) fn build_graph<'a>(
+    nodes: &'a [Node],
+    edge_specs: &[EdgeSpec],
+    start: usize,
+    end: usize,
+) -> (Vec<Edge<'a>>, Arena) {
+    let edges: Vec<Edge<'a>> = edge_specs
+        .iter()
+        .map(|spec| Edge::new(spec.weight, &nodes[spec.start], &nodes[spec.end]))
+        .collect();
+
+    let node_refs: Vec<&Node> = nodes.iter().collect();
+    let edge_refs: Vec<&Edge> = edges.iter().collect();
+    let arena = Arena::new(&nodes[start], &nodes[end])
+        .add_nodes(&node_refs)
+        .add_edges(&edge_refs);
+
+    (edges, arena)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mst::minimum_spanning_tree;
+
+    #[test]
+    fn edge_list_dedupes_labels_and_builds_a_searchable_arena() {
+        let text = "\
+            book poster 0.0\n\
+            book lp 5.0\n\
+            poster bassguitar 30.0\n\
+            poster drums 35.0\n\
+            lp drums 20.0\n\
+            lp bassguitar 15.0\n\
+            bassguitar piano 20.0\n\
+            drums piano 10.0\n\
+        ";
+
+        let (nodes, specs) = parse_edge_list(text);
+        let start = index_of_label(&nodes, "book");
+        let end = index_of_label(&nodes, "piano");
+        let (edges, arena) = build_graph(&nodes, &specs, start, end);
+
+        assert_eq!(nodes.len(), 6);
+        assert_eq!(edges.len(), 8);
+        assert_eq!("book -> lp -> drums -> piano -> ", arena.djikstra());
+    }
+
+    #[test]
+    fn adjacency_matrix_zero_cells_mean_no_edge() {
+        let text = "\
+            0 1.0 0\n\
+            0 0   2.0\n\
+            0 0   0\n\
+        ";
+
+        let (nodes, specs) = parse_adjacency_matrix(text);
+        let (edges, arena) = build_graph(&nodes, &specs, 0, 2);
+
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(edges.len(), 2);
+        assert_eq!("0 -> 1 -> 2 -> ", arena.djikstra());
+    }
+
+    #[test]
+    fn parsed_edges_feed_straight_into_minimum_spanning_tree() {
+        let text = "\
+            a b 1.0\n\
+            b c 2.0\n\
+            c d 3.0\n\
+            a d 4.0\n\
+            a c 5.0\n\
+        ";
+
+        let (nodes, specs) = parse_edge_list(text);
+        let (edges, _arena) = build_graph(&nodes, &specs, 0, 0);
+
+        let node_refs: Vec<&Node> = nodes.iter().collect();
+        let edge_refs: Vec<&Edge> = edges.iter().collect();
+        let mst = minimum_spanning_tree(&node_refs, &edge_refs);
+
+        assert_eq!(mst.edges.len(), 3);
+        assert_eq!(mst.total_weight, 6.0);
+    }
+}
@@ -0,0 +1,74 @@
+use std::cmp::Ordering;
+
+/// A disjoint-set over the dense node indices `0..size`. Supports path
+/// compression (each visited node is pointed directly at its root during
+/// `find`) and union-by-rank (the shallower tree is attached under the
+/// deeper one), keeping both operations close to O(1) amortized.
+#[derive(Debug)]
+pub(crate) struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+}
+
+impl UnionFind {
+    pub(crate) fn new(size: usize) -> UnionFind {
+        UnionFind {
+            parent: (0..size).collect(),
+            rank: vec![0; size],
+        }
+    }
+
+    pub(crate) fn find(&mut self, node: usize) -> usize {
+        if self.parent[node] != node {
+            self.parent[node] = self.find(self.parent[node]);
+        }
+        self.parent[node]
+    }
+
+    /// Unions the sets containing `a` and `b`. Returns `false` without
+    /// modifying anything if they were already in the same set.
+    pub(crate) fn union(&mut self, a: usize, b: usize) -> bool {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a == root_b {
+            return false;
+        }
+
+        match self.rank[root_a].cmp(&self.rank[root_b]) {
+            Ordering::Less => self.parent[root_a] = root_b,
+            Ordering::Greater => self.parent[root_b] = root_a,
+            Ordering::Equal => {
+                self.parent[root_b] = root_a;
+                self.rank[root_a] += 1;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_merges_sets_and_find_agrees_afterwards() {
+        let mut forest = UnionFind::new(5);
+        assert_ne!(forest.find(0), forest.find(1));
+
+        assert!(forest.union(0, 1));
+        assert_eq!(forest.find(0), forest.find(1));
+
+        assert!(forest.union(1, 2));
+        assert_eq!(forest.find(0), forest.find(2));
+
+        assert_ne!(forest.find(0), forest.find(3));
+    }
+
+    #[test]
+    fn union_of_already_joined_sets_is_a_no_op() {
+        let mut forest = UnionFind::new(3);
+        assert!(forest.union(0, 1));
+        assert!(!forest.union(0, 1));
+        assert!(!forest.union(1, 0));
+    }
+}